@@ -29,7 +29,30 @@ impl FeeCalculator {
         note = "Please do not use, will no longer be available in the future"
     )]
     pub fn calculate_fee(&self, message: &Message) -> u64 {
-        DEFAULT_TARGET_LAMPORTS_PER_SIGNATURE
+        let num_signatures = u64::from(message.header.num_required_signatures)
+            + self.num_precompile_signatures(message);
+        self.lamports_per_signature * num_signatures
+    }
+
+    /// Sum the signatures claimed by precompile instructions (secp256k1, ed25519) in `message`.
+    /// Each such instruction's first data byte is the number of signatures it covers.
+    fn num_precompile_signatures(&self, message: &Message) -> u64 {
+        message
+            .instructions
+            .iter()
+            .filter_map(|instruction| {
+                let program_id = message
+                    .account_keys
+                    .get(instruction.program_id_index as usize)?;
+                if *program_id != secp256k1_program::id() && *program_id != ed25519_program::id() {
+                    return None;
+                }
+                instruction
+                    .data
+                    .first()
+                    .map(|&num_signatures| u64::from(num_signatures))
+            })
+            .sum()
     }
 }
 
@@ -55,6 +78,12 @@ pub struct FeeRateGovernor {
 
     // What portion of collected fees are to be destroyed, as a fraction of std::u8::MAX
     pub burn_percent: u8,
+
+    // When true, `new_derived` recomputes `lamports_per_signature` from cluster load instead
+    // of pinning it to `CONSTANT_TRANSACTION_FEE_LAMPORTS`. Operators that want a fixed-fee
+    // cluster (the default) leave this `false`.
+    #[serde(default)]
+    pub dynamic: bool,
 }
 
 // ============================================================================
@@ -83,6 +112,7 @@ impl Default for FeeRateGovernor {
             min_lamports_per_signature: CONSTANT_TRANSACTION_FEE_LAMPORTS,
             max_lamports_per_signature: CONSTANT_TRANSACTION_FEE_LAMPORTS,
             burn_percent: DEFAULT_BURN_PERCENT,
+            dynamic: false,
         }
     }
 }
@@ -97,30 +127,103 @@ impl FeeRateGovernor {
             target_signatures_per_slot: 0, // Disable dynamic adjustment
             min_lamports_per_signature: CONSTANT_TRANSACTION_FEE_LAMPORTS,
             max_lamports_per_signature: CONSTANT_TRANSACTION_FEE_LAMPORTS,
+            dynamic: false,
             ..FeeRateGovernor::default()
         };
 
         Self::new_derived(&base_fee_rate_governor, 0)
     }
 
+    /// Like [`FeeRateGovernor::new`], but lets genesis configure a non-zero `burn_percent` so the
+    /// cluster can run a deflationary fee policy (the original design allowed up to 50%).
+    pub fn new_with_burn_percent(
+        target_lamports_per_signature: u64,
+        target_signatures_per_slot: u64,
+        burn_percent: u8,
+    ) -> Self {
+        Self {
+            burn_percent,
+            ..Self::new(target_lamports_per_signature, target_signatures_per_slot)
+        }
+    }
+
+    /// Create a load-adaptive `FeeRateGovernor` that steps `lamports_per_signature` toward a
+    /// desired fee of `target_lamports_per_signature` scaled by `latest_signatures_per_slot /
+    /// target_signatures_per_slot` (clamped to `[target / 2, target * 10]`) instead of pinning it
+    /// to the constant fee. Opt in by setting `dynamic: true` (and a non-zero `target_signatures_per_slot`)
+    /// on `base_fee_rate_governor` before deriving.
     pub fn new_derived(
         base_fee_rate_governor: &FeeRateGovernor,
         latest_signatures_per_slot: u64,
     ) -> Self {
         let mut me = base_fee_rate_governor.clone();
 
-        // Always use constant fee from global constant
-        // Disable dynamic fee adjustment regardless of traffic
-        me.lamports_per_signature = CONSTANT_TRANSACTION_FEE_LAMPORTS;
-        me.target_lamports_per_signature = CONSTANT_TRANSACTION_FEE_LAMPORTS;
-        me.min_lamports_per_signature = CONSTANT_TRANSACTION_FEE_LAMPORTS;
-        me.max_lamports_per_signature = CONSTANT_TRANSACTION_FEE_LAMPORTS;
-        me.target_signatures_per_slot = 0; // Disable dynamic adjustment
-        
-        debug!(
-            "new_derived(): lamports_per_signature: {} (constant fee)",
-            me.lamports_per_signature
-        );
+        if me.dynamic && me.target_signatures_per_slot > 0 {
+            // `FeeRateGovernor`'s fields are all `pub`, so a caller can hand in an arbitrary
+            // `target_lamports_per_signature` without going through `new()`'s 10,000-lamport pin.
+            // Do this arithmetic in u128/i128, as `apply_fees` does, so a large target combined
+            // with `latest_signatures_per_slot` near `u32::MAX` can't overflow a `u64`/`i64` or
+            // flip the sign of `gap`; only narrow back to `u64` once every value is clamped.
+            let target_lamports_per_signature = u128::from(me.target_lamports_per_signature);
+
+            // What a reasonable fee should be based on the current network conditions.
+            me.min_lamports_per_signature =
+                std::cmp::max(1, me.target_lamports_per_signature / 2);
+            me.max_lamports_per_signature =
+                std::cmp::min(u128::from(u64::MAX), target_lamports_per_signature * 10) as u64;
+
+            let latest_signatures_per_slot = u128::from(std::cmp::min(
+                latest_signatures_per_slot,
+                u64::from(u32::MAX),
+            ));
+            let desired_lamports_per_signature = std::cmp::min(
+                u128::from(me.max_lamports_per_signature),
+                std::cmp::max(
+                    u128::from(me.min_lamports_per_signature),
+                    target_lamports_per_signature * latest_signatures_per_slot
+                        / u128::from(me.target_signatures_per_slot),
+                ),
+            ) as u64;
+
+            let gap = i128::from(desired_lamports_per_signature)
+                - i128::from(me.lamports_per_signature);
+            if gap == 0 {
+                me.lamports_per_signature = desired_lamports_per_signature;
+            } else {
+                // Step the fee toward the desired fee by 5% of target_lamports_per_signature
+                // (at least 1 lamport), then clamp it to [min, max].
+                let gap_adjust =
+                    i128::from(std::cmp::max(1, me.target_lamports_per_signature / 20));
+                let adjusted_lamports_per_signature = (i128::from(me.lamports_per_signature)
+                    + if gap > 0 { gap_adjust } else { -gap_adjust })
+                .max(0) as u128;
+
+                me.lamports_per_signature = std::cmp::min(
+                    u128::from(me.max_lamports_per_signature),
+                    std::cmp::max(
+                        u128::from(me.min_lamports_per_signature),
+                        adjusted_lamports_per_signature,
+                    ),
+                ) as u64;
+            }
+
+            debug!(
+                "new_derived(): lamports_per_signature: {} (dynamic; desired {})",
+                me.lamports_per_signature, desired_lamports_per_signature
+            );
+        } else {
+            // Always use constant fee from global constant
+            me.lamports_per_signature = CONSTANT_TRANSACTION_FEE_LAMPORTS;
+            me.target_lamports_per_signature = CONSTANT_TRANSACTION_FEE_LAMPORTS;
+            me.min_lamports_per_signature = CONSTANT_TRANSACTION_FEE_LAMPORTS;
+            me.max_lamports_per_signature = CONSTANT_TRANSACTION_FEE_LAMPORTS;
+            me.target_signatures_per_slot = 0; // Disable dynamic adjustment
+
+            debug!(
+                "new_derived(): lamports_per_signature: {} (constant fee)",
+                me.lamports_per_signature
+            );
+        }
         me
     }
 
@@ -137,12 +240,36 @@ impl FeeRateGovernor {
         (fees - burned, burned)
     }
 
+    /// Split `total_collected` fee lamports between the validator and the portion to burn
+    /// (permanently removed from supply), per `burn_percent`. Uses a `u128` intermediate so the
+    /// split can't overflow regardless of `total_collected`, and rounds down deterministically.
+    pub fn apply_fees(&self, total_collected: u64) -> FeeSplit {
+        let burn_percent = u128::from(std::cmp::min(self.burn_percent, 100));
+        let burned = (u128::from(total_collected) * burn_percent / 100) as u64;
+        let validator_share = total_collected.checked_sub(burned).unwrap_or(0);
+        FeeSplit {
+            validator_share,
+            burned,
+        }
+    }
+
     /// create a FeeCalculator based on current cluster signature throughput
     pub fn create_fee_calculator(&self) -> FeeCalculator {
         FeeCalculator::new(self.lamports_per_signature)
     }
 }
 
+/// The result of [`FeeRateGovernor::apply_fees`]: how a total collected fee is divided between
+/// the validator and the amount burned (destroyed) to implement deflationary fee policy.
+#[derive(Serialize, Deserialize, Default, PartialEq, Eq, Clone, Copy, Debug, AbiExample)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeSplit {
+    /// Portion of the collected fees credited to the validator.
+    pub validator_share: u64,
+    /// Portion of the collected fees destroyed, removing it from supply.
+    pub burned: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use {
@@ -162,6 +289,68 @@ mod tests {
         assert_eq!(fee_rate_governor.burn(2), (0, 2));
     }
 
+    #[test]
+    fn test_fee_rate_governor_apply_fees() {
+        let mut fee_rate_governor = FeeRateGovernor::default();
+
+        fee_rate_governor.burn_percent = 50;
+        assert_eq!(
+            fee_rate_governor.apply_fees(101),
+            FeeSplit {
+                validator_share: 51,
+                burned: 50,
+            }
+        );
+
+        fee_rate_governor.burn_percent = 100;
+        assert_eq!(
+            fee_rate_governor.apply_fees(101),
+            FeeSplit {
+                validator_share: 0,
+                burned: 101,
+            }
+        );
+
+        fee_rate_governor.burn_percent = 0;
+        assert_eq!(
+            fee_rate_governor.apply_fees(101),
+            FeeSplit {
+                validator_share: 101,
+                burned: 0,
+            }
+        );
+
+        // An out-of-range burn_percent (the field is a raw u8, not validated at construction)
+        // must still be capped at 100% so burned can never exceed total_collected.
+        fee_rate_governor.burn_percent = 150;
+        assert_eq!(
+            fee_rate_governor.apply_fees(101),
+            FeeSplit {
+                validator_share: 0,
+                burned: 101,
+            }
+        );
+    }
+
+    #[test]
+    fn test_fee_rate_governor_new_with_burn_percent() {
+        let fee_rate_governor = FeeRateGovernor::new_with_burn_percent(
+            CONSTANT_TRANSACTION_FEE_LAMPORTS,
+            DEFAULT_TARGET_SIGNATURES_PER_SLOT,
+            50,
+        );
+        assert_eq!(fee_rate_governor.burn_percent, 50);
+        assert_eq!(
+            fee_rate_governor.lamports_per_signature,
+            CONSTANT_TRANSACTION_FEE_LAMPORTS
+        );
+        assert_eq!(
+            fee_rate_governor.target_lamports_per_signature,
+            CONSTANT_TRANSACTION_FEE_LAMPORTS
+        );
+        assert_eq!(fee_rate_governor.target_signatures_per_slot, 0);
+    }
+
     #[test]
     #[allow(deprecated)]
     fn test_fee_calculator_calculate_fee() {
@@ -257,6 +446,7 @@ mod tests {
         let mut f = FeeRateGovernor {
             target_lamports_per_signature: 100,
             target_signatures_per_slot: 100,
+            dynamic: true,
             ..FeeRateGovernor::default()
         };
         f = FeeRateGovernor::new_derived(&f, 0);
@@ -312,4 +502,21 @@ mod tests {
             count += 1;
         }
     }
+
+    #[test]
+    fn test_fee_rate_governor_derived_adjust_no_overflow() {
+        // A multi-ROX target_lamports_per_signature combined with latest_signatures_per_slot
+        // near u32::MAX must not overflow the u64/i64 arithmetic in new_derived (the same class
+        // of bug apply_fees guards against with a u128 intermediate).
+        let f = FeeRateGovernor {
+            target_lamports_per_signature: 5_000_000_000,
+            target_signatures_per_slot: 1,
+            lamports_per_signature: 5_000_000_000,
+            dynamic: true,
+            ..FeeRateGovernor::default()
+        };
+        let derived = FeeRateGovernor::new_derived(&f, std::u64::MAX);
+        assert!(derived.lamports_per_signature <= derived.max_lamports_per_signature);
+        assert!(derived.lamports_per_signature >= derived.min_lamports_per_signature);
+    }
 }
@@ -15,7 +15,25 @@ pub fn rox_to_lamports(rox: f64) -> u64 {
     (rox * LAMPORTS_PER_ROX as f64) as u64
 }
 
+/// Convert native tokens (ROX) into fractional native tokens (lamports), returning `None` for
+/// non-finite or negative inputs and for results that don't fit in a `u64` instead of silently
+/// saturating. Any fraction of a lamport is truncated.
+pub fn checked_rox_to_lamports(rox: f64) -> Option<u64> {
+    if !rox.is_finite() || (rox.is_sign_negative() && rox != 0.0) {
+        return None;
+    }
+    let lamports = rox * LAMPORTS_PER_ROX as f64;
+    // `u64::MAX as f64` rounds up to 2^64, one past the largest representable `u64`, so compare
+    // against 2^64 directly rather than let a borderline value slip past the cast below.
+    if lamports >= 2f64.powi(64) {
+        return None;
+    }
+    Some(lamports as u64)
+}
+
 use std::fmt::{Debug, Display, Formatter, Result};
+use std::str::FromStr;
+
 pub struct Rox(pub u64);
 
 impl Rox {
@@ -40,3 +58,99 @@ impl Debug for Rox {
         self.write_in_rox(f)
     }
 }
+
+/// Error returned by [`Rox::from_str`] when a `◎`-prefixed decimal string can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ParseRoxError {
+    #[error("invalid ROX amount")]
+    InvalidFormat,
+    #[error("ROX amounts support at most 9 fractional digits")]
+    TooManyDecimals,
+    #[error("ROX amount overflows lamports")]
+    Overflow,
+}
+
+impl FromStr for Rox {
+    type Err = ParseRoxError;
+
+    /// Parse the `◎123.000000001` form that [`Rox`] displays, without any float rounding. The
+    /// leading `◎` is optional and at most 9 fractional digits are accepted.
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.strip_prefix('◎').unwrap_or(s);
+        let (whole, frac) = s.split_once('.').unwrap_or((s, ""));
+
+        if frac.len() > 9 {
+            return Err(ParseRoxError::TooManyDecimals);
+        }
+        if whole.is_empty() {
+            return Err(ParseRoxError::InvalidFormat);
+        }
+
+        let whole: u64 = whole.parse().map_err(|_| ParseRoxError::InvalidFormat)?;
+        let frac_value: u64 = if frac.is_empty() {
+            0
+        } else {
+            frac.parse().map_err(|_| ParseRoxError::InvalidFormat)?
+        };
+        let fractional_lamports = frac_value * 10u64.pow(9 - frac.len() as u32);
+
+        whole
+            .checked_mul(LAMPORTS_PER_ROX)
+            .and_then(|whole_lamports| whole_lamports.checked_add(fractional_lamports))
+            .map(Rox)
+            .ok_or(ParseRoxError::Overflow)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rox_from_str_round_trip() {
+        assert_eq!("◎1.5".parse::<Rox>().unwrap().0, 1_500_000_000);
+        assert_eq!(
+            Rox(1_500_000_000).to_string().parse::<Rox>().unwrap().0,
+            1_500_000_000
+        );
+    }
+
+    #[test]
+    fn test_rox_from_str_without_prefix() {
+        assert_eq!("1.5".parse::<Rox>().unwrap().0, 1_500_000_000);
+    }
+
+    #[test]
+    fn test_rox_from_str_too_many_decimals() {
+        assert_eq!(
+            "◎1.0000000001".parse::<Rox>().unwrap_err(),
+            ParseRoxError::TooManyDecimals
+        );
+    }
+
+    #[test]
+    fn test_rox_from_str_overflow() {
+        // Fits in a u64 on its own, but overflows once multiplied by LAMPORTS_PER_ROX.
+        assert_eq!(
+            "◎18446744074".parse::<Rox>().unwrap_err(),
+            ParseRoxError::Overflow
+        );
+    }
+
+    #[test]
+    fn test_rox_from_str_invalid_format() {
+        assert_eq!(
+            "◎garbage".parse::<Rox>().unwrap_err(),
+            ParseRoxError::InvalidFormat
+        );
+    }
+
+    #[test]
+    fn test_checked_rox_to_lamports() {
+        assert_eq!(checked_rox_to_lamports(1.5), Some(1_500_000_000));
+        assert_eq!(checked_rox_to_lamports(f64::NAN), None);
+        assert_eq!(checked_rox_to_lamports(-1.0), None);
+        assert_eq!(checked_rox_to_lamports(f64::INFINITY), None);
+        assert_eq!(checked_rox_to_lamports(-0.0), Some(0));
+    }
+}